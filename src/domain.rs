@@ -0,0 +1,191 @@
+//! Evaluating polynomials on a multiplicative domain of roots of unity, i.e. converting between
+//! the monomial and the Lagrange/evaluation basis, using the radix-2 number-theoretic/fast Fourier
+//! transform.
+//!
+//! This is the sibling of the arithmetic-progression tabulation in the crate root: instead of an
+//! additive domain `x0, x0+h, x0+2h, ...`, it evaluates at all `n`-th roots of unity
+//! `omega^0, omega^1, ..., omega^(n-1)`, which is the domain finite-field users (KZG/PLONK/sumcheck
+//! style protocols) actually need.
+
+use std::ops::{Add, Mul, Sub};
+
+/// Evaluate the polynomial given by `coefficients` (padded with zeros to length `n` if shorter) at
+/// every `n`-th root of unity `omega^0, omega^1, ..., omega^(n-1)`, in O(n log n) using the
+/// iterative Cooley-Tukey NTT/FFT.
+///
+/// `n` must be a power of two, and panics otherwise, or if `coefficients.len() > n`.
+///
+/// `omega` must additionally be a primitive `n`-th root of unity of the coefficient type; this
+/// precondition is not checked, so behavior is unspecified (not a panic) if it does not hold.
+pub fn evaluate_on_domain<C: Clone>(coefficients: &[C], omega: &C, n: usize) -> Vec<C>
+where
+    C: From<usize>,
+    for<'a> C: Mul<&'a C, Output = C>,
+    for<'a> &'a C: Add<&'a C, Output = C>,
+    for<'a> &'a C: Sub<&'a C, Output = C>,
+{
+    assert!(n.is_power_of_two());
+    assert!(coefficients.len() <= n);
+    let mut values = coefficients.to_vec();
+    values.resize(n, C::from(0));
+    ntt(&mut values, omega, n);
+    values
+}
+
+/// The inverse of [`evaluate_on_domain`]: recover the monomial coefficients from the `n` values of
+/// a degree-`<n` polynomial at `omega^0, omega^1, ..., omega^(n-1)`.
+///
+/// `omega_inv` must be the inverse of the `omega` used to produce `values`, and `n_inv` the inverse
+/// of `n` in the coefficient field; both are left for the caller to supply, since computing an
+/// inverse isn't something this crate's `C: Mul/Add/Sub` bounds can express in general.
+pub fn interpolate_on_domain<C: Clone>(values: &[C], omega_inv: &C, n_inv: &C, n: usize) -> Vec<C>
+where
+    C: From<usize>,
+    for<'a> C: Mul<&'a C, Output = C>,
+    for<'a> &'a C: Add<&'a C, Output = C>,
+    for<'a> &'a C: Sub<&'a C, Output = C>,
+{
+    assert!(n.is_power_of_two());
+    assert_eq!(values.len(), n);
+    let mut coefficients = values.to_vec();
+    ntt(&mut coefficients, omega_inv, n);
+    coefficients.into_iter().map(|c| c * n_inv).collect()
+}
+
+/// In-place iterative Cooley-Tukey NTT/FFT of `a` (length `n`, a power of two) with respect to the
+/// primitive `n`-th root of unity `omega`.
+fn ntt<C: Clone>(a: &mut [C], omega: &C, n: usize)
+where
+    C: From<usize>,
+    for<'a> C: Mul<&'a C, Output = C>,
+    for<'a> &'a C: Add<&'a C, Output = C>,
+    for<'a> &'a C: Sub<&'a C, Output = C>,
+{
+    bit_reverse_permute(a);
+    let mut len = 2;
+    while len <= n {
+        let w_len = pow(omega, n / len);
+        let mut start = 0;
+        while start < n {
+            let mut w = C::from(1);
+            for j in 0..len / 2 {
+                let u = a[start + j].clone();
+                let v = a[start + j + len / 2].clone() * &w;
+                a[start + j] = &u + &v;
+                a[start + j + len / 2] = &u - &v;
+                w = w * &w_len;
+            }
+            start += len;
+        }
+        len *= 2;
+    }
+}
+
+/// Permute `a` (length a power of two) into bit-reversed order.
+fn bit_reverse_permute<C>(a: &mut [C]) {
+    let n = a.len();
+    if n <= 1 {
+        return;
+    }
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+}
+
+/// Compute `base^exp` by exponentiation by squaring.
+fn pow<C: Clone>(base: &C, mut exp: usize) -> C
+where
+    C: From<usize>,
+    for<'a> C: Mul<&'a C, Output = C>,
+{
+    let mut result = C::from(1);
+    let mut b = base.clone();
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * &b;
+        }
+        b = b.clone() * &b;
+        exp >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Fp(i64);
+
+#[cfg(test)]
+const P: i64 = 17;
+
+#[cfg(test)]
+impl From<usize> for Fp {
+    fn from(value: usize) -> Self {
+        Fp((value as i64).rem_euclid(P))
+    }
+}
+
+#[cfg(test)]
+impl Mul<&Fp> for Fp {
+    type Output = Fp;
+    fn mul(self, rhs: &Fp) -> Fp {
+        Fp((self.0 * rhs.0).rem_euclid(P))
+    }
+}
+
+#[cfg(test)]
+impl<'a> Add<&'a Fp> for &'a Fp {
+    type Output = Fp;
+    fn add(self, rhs: &'a Fp) -> Fp {
+        Fp((self.0 + rhs.0).rem_euclid(P))
+    }
+}
+
+#[cfg(test)]
+impl<'a> Sub<&'a Fp> for &'a Fp {
+    type Output = Fp;
+    fn sub(self, rhs: &'a Fp) -> Fp {
+        Fp((self.0 - rhs.0).rem_euclid(P))
+    }
+}
+
+#[test]
+fn test_evaluate_on_domain_matches_naive_evaluation() {
+    // 17 is prime with 17 - 1 = 16 divisible by 8, and 9 has order 8 mod 17.
+    let omega = Fp(9);
+    let n = 8;
+    let coefficients = [Fp(1), Fp(2), Fp(3), Fp(4), Fp(5)];
+
+    let values = evaluate_on_domain(&coefficients, &omega, n);
+    for (k, value) in values.iter().enumerate() {
+        let x = pow(&omega, k);
+        assert_eq!(*value, evaluate_naive(&coefficients, &x));
+    }
+}
+
+#[cfg(test)]
+fn evaluate_naive(coefficients: &[Fp], x: &Fp) -> Fp {
+    let mut result = Fp(0);
+    let mut power = Fp(1);
+    for c in coefficients {
+        result = &result + &(*c * &power);
+        power = power * x;
+    }
+    result
+}
+
+#[test]
+fn test_interpolate_on_domain_roundtrip() {
+    let omega = Fp(9);
+    let omega_inv = Fp(2);
+    let n_inv = Fp(15);
+    let n = 8;
+
+    let coefficients = [Fp(1), Fp(2), Fp(3), Fp(4), Fp(5), Fp(0), Fp(0), Fp(0)];
+    let values = evaluate_on_domain(&coefficients, &omega, n);
+    let recovered = interpolate_on_domain(&values, &omega_inv, &n_inv, n);
+    assert_eq!(recovered, coefficients);
+}
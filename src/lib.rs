@@ -1,5 +1,12 @@
+// This crate consistently declares the `Clone`/`Copy`-style bounds on the generic parameter itself
+// and the arithmetic (`Add`/`Mul`/...) bounds in a separate `where` clause, grouping impls by which
+// operators they need; clippy's `multiple_bound_locations` considers that split itself a lint.
+#![allow(clippy::multiple_bound_locations)]
+
 use std::iter::successors;
-use std::ops::{Add, Mul, Sub};
+use std::ops::{Add, Div, Mul, Sub};
+
+pub mod domain;
 
 /// This iterator yields evaluations of a polynomial at points in an arithmetic progression, e.g., x0, x0+h, x0+2h, ...
 /// This is generally faster than just evaluating each point naively when evaluating more points than the degree of the polynomial.
@@ -23,7 +30,10 @@ where
 {
     /// Construct a new iterator which yields the evaluations of the polynomial defined by the `coefficients`
     /// on the points `initial`, `initial + step`, `initial + 2*step`, ... .
-    pub fn new(coefficients: &[C], initial: C, step: C) -> Self {
+    ///
+    /// `coefficients` may be a plain slice/array or a [`Polynomial`].
+    pub fn new(coefficients: impl AsRef<[C]>, initial: C, step: C) -> Self {
+        let coefficients = coefficients.as_ref();
         // Compute initial values (see exercise 7 in 4.6.4 of TAOCP)
         let mut state = successors(Some(initial.clone()), |x| Some(x + &step))
             .take(coefficients.len())
@@ -62,6 +72,206 @@ where
     }
 }
 
+impl<C: Clone> PolynomialEvaluator<C>
+where
+    for<'a> C: Mul<&'a C, Output = C>,
+    for<'a> C: Add<&'a C, Output = C>,
+    for<'a> &'a C: Add<&'a C, Output = C>,
+    for<'a> &'a C: Sub<&'a C, Output = C>,
+{
+    /// Construct a new iterator from already tabulated `samples` at `initial`, `initial + step`, ... ,
+    /// reconstructing the degree-`samples.len() - 1` polynomial that produced them.
+    ///
+    /// This is the dual of [`PolynomialEvaluator::new`]: instead of evaluating a known polynomial at
+    /// `samples.len()` points to seed the forward-difference table, the table is seeded directly from
+    /// the given values. The returned iterator tabulates from `initial` onwards: it first yields the
+    /// given samples again and then continues past `samples.last()`, and
+    /// [`PolynomialEvaluator::coefficients`] recovers the monomial coefficients of the polynomial.
+    pub fn from_samples(samples: &[C], initial: C, step: C) -> Self {
+        let mut state = samples.to_vec();
+        for k in 1..state.len() {
+            for j in (k..state.len()).rev() {
+                state[j] = &state[j] - &state[j - 1];
+            }
+        }
+        Self {
+            state,
+            first: true,
+            input: initial,
+            step,
+        }
+    }
+}
+
+impl<C: Clone> PolynomialEvaluator<C>
+where
+    for<'a> C: Mul<&'a C, Output = C>,
+    for<'a> C: Add<&'a C, Output = C>,
+    for<'a> C: Div<&'a C, Output = C>,
+    for<'a> &'a C: Add<&'a C, Output = C>,
+    for<'a> &'a C: Sub<&'a C, Output = C>,
+    C: From<usize>,
+{
+    /// Recover the monomial coefficients `[c0, c1, ..., cn]` of the polynomial whose forward-difference
+    /// table is held by this evaluator, i.e. the polynomial such that `c0 + c1*x + ... + cn*x^n` agrees
+    /// with the tabulated samples.
+    ///
+    /// This inverts Newton's forward-difference formula `f(x0 + s*h) = sum_k C(s,k) * delta^k y0`, where
+    /// `delta^k y0` is the `k`-th forward difference (already held in `state`) and `C(s,k)` is the falling
+    /// factorial `s*(s-1)*...*(s-k+1)/k!` with `s = (x - x0)/h`. Each falling factorial is expanded into
+    /// powers of `s` via the Stirling numbers of the first kind, and each power of `s` is then expanded
+    /// into powers of `x` via `s^k = ((x - x0)/h)^k`.
+    pub fn coefficients(&self) -> Vec<C> {
+        let n = self.state.len();
+        let diffs = &self.state;
+        let x0 = &self.input;
+        let h = &self.step;
+
+        // Stirling numbers of the first kind, stirling[k][i] = s(k, i), via the recurrence
+        // s(k, i) = s(k-1, i-1) - (k-1) * s(k-1, i).
+        let mut stirling = vec![vec![C::from(0); n]; n];
+        stirling[0][0] = C::from(1);
+        for k in 1..n {
+            for i in 0..=k {
+                let shifted = if i > 0 {
+                    stirling[k - 1][i - 1].clone()
+                } else {
+                    C::from(0)
+                };
+                let scaled = stirling[k - 1][i].clone() * &C::from(k - 1);
+                stirling[k][i] = &shifted - &scaled;
+            }
+        }
+
+        let mut factorial = vec![C::from(1); n];
+        for k in 1..n {
+            factorial[k] = factorial[k - 1].clone() * &C::from(k);
+        }
+
+        // s_pow holds the coefficients, in ascending order, of (x - x0)^i as a polynomial in x.
+        let neg_x0 = &C::from(0) - x0;
+        let linear = vec![neg_x0, C::from(1)];
+        let mut s_pow = vec![C::from(1)];
+        let mut h_pow = C::from(1);
+
+        // brackets[i] = sum_{k=i}^{n-1} diffs[k] * stirling[k][i] / factorial[k], accumulated by
+        // row `k` instead of by column `i` so each row's coefficients are reached through a plain
+        // `enumerate()` rather than an `i`-indexed subscript into `stirling`.
+        let mut brackets = vec![C::from(0); n];
+        for (k, row) in stirling.iter().enumerate() {
+            let scaled = diffs[k].clone() / &factorial[k];
+            for (i, coefficient) in row.iter().enumerate().take(k + 1) {
+                let term = scaled.clone() * coefficient;
+                brackets[i] = &brackets[i] + &term;
+            }
+        }
+
+        let mut result = vec![C::from(0); n];
+        for (i, bracket) in brackets.iter().enumerate() {
+            // bracket * s^i = bracket * (x - x0)^i / h^i
+            for (m, coefficient) in s_pow.iter().enumerate() {
+                let contribution = (bracket.clone() * coefficient) / &h_pow;
+                result[m] = &result[m] + &contribution;
+            }
+            if i + 1 < n {
+                s_pow = multiply(&s_pow, &linear);
+                h_pow = h_pow * h;
+            }
+        }
+        result
+    }
+}
+
+/// Tabulate `count` evaluations of the polynomial given by `coefficients`, starting at `initial`
+/// and stepping by `step`, the same as `PolynomialEvaluator::new(coefficients, initial,
+/// step).take(count)`, but computed across `threads` contiguous blocks in parallel.
+///
+/// `count` is partitioned into `threads` roughly equal contiguous blocks; each block re-seeds its
+/// own finite-difference state from scratch at its starting point (exactly as
+/// [`PolynomialEvaluator::new`] does) and then runs the cheap additive recurrence for the rest of
+/// the block, so blocks have no cross-thread dependency and their results simply concatenate in
+/// order. With exact arithmetic (integers, finite fields) the result is therefore identical to the
+/// sequential iterator for any `threads`; with floating-point `C`, a block's starting point and its
+/// Horner-seeded values can round differently from the sequential running sums.
+#[cfg(feature = "rayon")]
+pub fn tabulate_parallel<C>(coefficients: &[C], initial: C, step: C, count: usize, threads: usize) -> Vec<(C, C)>
+where
+    C: Clone + Send + Sync,
+    for<'a> C: Mul<&'a C, Output = C>,
+    for<'a> C: Add<&'a C, Output = C>,
+    for<'a> &'a C: Add<&'a C, Output = C>,
+    for<'a> &'a C: Sub<&'a C, Output = C>,
+{
+    use rayon::prelude::*;
+
+    if count == 0 || threads == 0 {
+        return Vec::new();
+    }
+    let block_size = count.div_ceil(threads);
+    (0..threads)
+        .into_par_iter()
+        .map(|t| {
+            let start = t * block_size;
+            if start >= count {
+                return Vec::new();
+            }
+            let len = block_size.min(count - start);
+            let block_initial = advance_by(&initial, &step, start);
+            PolynomialEvaluator::new(coefficients, block_initial, step.clone())
+                .take(len)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+        .concat()
+}
+
+/// Compute `initial + n*step` in `O(log n)` additions by doubling `step`, the additive analogue of
+/// the exponentiation-by-squaring in [`domain::pow`]: instead of repeatedly adding `step` to
+/// `initial` `n` times, `step` is itself doubled `O(log n)` times and the doublings whose bit is
+/// set in `n` are summed, so fast-forwarding a block's starting point costs `O(log n)` rather than
+/// `O(n)` and the per-thread work in [`tabulate_parallel`] no longer grows with the block's offset.
+#[cfg(feature = "rayon")]
+fn advance_by<C: Clone>(initial: &C, step: &C, mut n: usize) -> C
+where
+    for<'a> &'a C: Add<&'a C, Output = C>,
+{
+    let mut term = step.clone();
+    let mut offset: Option<C> = None;
+    while n > 0 {
+        if n & 1 == 1 {
+            offset = Some(match &offset {
+                Some(sum) => sum + &term,
+                None => term.clone(),
+            });
+        }
+        n >>= 1;
+        if n > 0 {
+            term = &term + &term;
+        }
+    }
+    match offset {
+        Some(offset) => initial + &offset,
+        None => initial.clone(),
+    }
+}
+
+/// Multiply two polynomials given by their coefficients in ascending order.
+fn multiply<C: Clone>(a: &[C], b: &[C]) -> Vec<C>
+where
+    C: From<usize>,
+    for<'a> C: Mul<&'a C, Output = C>,
+    for<'a> &'a C: Add<&'a C, Output = C>,
+{
+    let mut result = vec![C::from(0); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            let term = x.clone() * y;
+            result[i + j] = &result[i + j] + &term;
+        }
+    }
+    result
+}
+
 /// Evaluate a polynomial using Horner's method.
 /// Panics if `coefficients` is empty.
 fn evaluate<C: Clone>(coefficients: &[C], input: &C) -> C
@@ -82,6 +292,364 @@ where
         })
 }
 
+/// A polynomial represented by its coefficients `[c0, c1, ..., cn]` in ascending order of degree,
+/// i.e. the polynomial `c0 + c1*x + ... + cn*x^n`.
+///
+/// This is a first-class algebraic object to build and combine polynomials with, rather than
+/// passing bare coefficient slices around; [`PolynomialEvaluator::new`] accepts a `&Polynomial<C>`
+/// directly, so tabulation can chain off constructed/combined polynomials.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polynomial<C> {
+    coefficients: Vec<C>,
+}
+
+impl<C> Polynomial<C> {
+    /// Construct a polynomial from its coefficients in ascending order of degree.
+    /// Panics if `coefficients` is empty.
+    pub fn new(coefficients: Vec<C>) -> Self {
+        assert!(!coefficients.is_empty());
+        Self { coefficients }
+    }
+
+    /// The coefficients of this polynomial in ascending order of degree.
+    pub fn coefficients(&self) -> &[C] {
+        &self.coefficients
+    }
+
+    /// The degree of this polynomial, i.e. one less than the number of coefficients.
+    ///
+    /// Trailing zero coefficients are not accounted for; call [`Polynomial::trim`] first if those
+    /// should be ignored.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+}
+
+impl<C> AsRef<[C]> for Polynomial<C> {
+    fn as_ref(&self) -> &[C] {
+        &self.coefficients
+    }
+}
+
+impl<C: Clone> Polynomial<C>
+where
+    for<'a> C: Mul<&'a C, Output = C>,
+    for<'a> C: Add<&'a C, Output = C>,
+{
+    /// Evaluate this polynomial at `x` using Horner's method.
+    pub fn eval(&self, x: &C) -> C {
+        evaluate(&self.coefficients, x)
+    }
+}
+
+impl<C: Clone + PartialEq> Polynomial<C>
+where
+    C: From<usize>,
+{
+    /// Remove trailing zero coefficients, leaving a single zero coefficient if the polynomial is
+    /// the zero polynomial.
+    pub fn trim(&mut self) {
+        let zero = C::from(0);
+        while self.coefficients.len() > 1 && *self.coefficients.last().unwrap() == zero {
+            self.coefficients.pop();
+        }
+    }
+}
+
+impl<C: Clone> Polynomial<C>
+where
+    for<'a> C: Mul<&'a C, Output = C>,
+{
+    /// Multiply every coefficient by `scalar`.
+    pub fn scale(&self, scalar: &C) -> Polynomial<C> {
+        Polynomial::new(self.coefficients.iter().map(|c| c.clone() * scalar).collect())
+    }
+}
+
+impl<C: Clone> Polynomial<C>
+where
+    for<'a> C: Div<&'a C, Output = C>,
+{
+    /// Divide every coefficient by `scalar`.
+    pub fn scale_div(&self, scalar: &C) -> Polynomial<C> {
+        Polynomial::new(self.coefficients.iter().map(|c| c.clone() / scalar).collect())
+    }
+}
+
+impl<C: Clone> Polynomial<C>
+where
+    C: From<usize>,
+    for<'a> C: Mul<&'a C, Output = C>,
+    for<'a> &'a C: Add<&'a C, Output = C>,
+{
+    /// Raise this polynomial to the power `exp`, by exponentiation by squaring.
+    pub fn pow(&self, exp: u32) -> Polynomial<C> {
+        let mut result = Polynomial::new(vec![C::from(1)]);
+        for bit in (0..u32::BITS - exp.leading_zeros()).rev() {
+            result = Polynomial::new(multiply(&result.coefficients, &result.coefficients));
+            if (exp >> bit) & 1 == 1 {
+                result = Polynomial::new(multiply(&result.coefficients, &self.coefficients));
+            }
+        }
+        result
+    }
+}
+
+// `Add`/`Sub`/`Mul` are exposed as named methods rather than `std::ops` impls: implementing those
+// traits directly on `&Polynomial<C>` with the same `for<'a> &'a C: Add<...>` bound shape used
+// elsewhere in this crate (e.g. `multiply`, `advance_along`) sends rustc's trait solver into an
+// unbounded search over `Polynomial<Polynomial<Polynomial<...>>>` and it overflows at compile
+// time, so the crate's own generic helpers stop type-checking.
+impl<C: Clone> Polynomial<C>
+where
+    C: From<usize>,
+    for<'a> &'a C: Add<&'a C, Output = C>,
+{
+    /// Add two polynomials, padding the shorter one with zero coefficients.
+    pub fn add(&self, rhs: &Polynomial<C>) -> Polynomial<C> {
+        let zero = C::from(0);
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        let coefficients = (0..len)
+            .map(|i| {
+                let a = self.coefficients.get(i).unwrap_or(&zero);
+                let b = rhs.coefficients.get(i).unwrap_or(&zero);
+                a + b
+            })
+            .collect();
+        Polynomial::new(coefficients)
+    }
+}
+
+impl<C: Clone> Polynomial<C>
+where
+    C: From<usize>,
+    for<'a> &'a C: Sub<&'a C, Output = C>,
+{
+    /// Subtract `rhs` from this polynomial, padding the shorter one with zero coefficients.
+    pub fn sub(&self, rhs: &Polynomial<C>) -> Polynomial<C> {
+        let zero = C::from(0);
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        let coefficients = (0..len)
+            .map(|i| {
+                let a = self.coefficients.get(i).unwrap_or(&zero);
+                let b = rhs.coefficients.get(i).unwrap_or(&zero);
+                a - b
+            })
+            .collect();
+        Polynomial::new(coefficients)
+    }
+}
+
+impl<C: Clone> Polynomial<C>
+where
+    C: From<usize>,
+    for<'a> C: Mul<&'a C, Output = C>,
+    for<'a> &'a C: Add<&'a C, Output = C>,
+{
+    /// Multiply two polynomials by convolving their coefficients.
+    pub fn mul(&self, rhs: &Polynomial<C>) -> Polynomial<C> {
+        Polynomial::new(multiply(&self.coefficients, &rhs.coefficients))
+    }
+}
+
+/// This iterator generalizes [`PolynomialEvaluator`] to a multivariate polynomial tabulated over a
+/// regular grid `(x0[0] + i0*h0, x0[1] + i1*h1, ...)`, evaluating one polynomial at every point of
+/// the product domain far more cheaply than evaluating each point from scratch.
+///
+/// The polynomial is given by its coefficient tensor `coefficients`, a `Vec<C>` in row-major order
+/// (the last axis varies fastest) with per-axis degree bounds `degrees`, so axis `a` holds
+/// `degrees[a] + 1` coefficients.
+///
+/// The iterator advances like an odometer: stepping the fastest axis applies the same additive
+/// recurrence as [`PolynomialEvaluator`] along that axis; when an axis reaches its requested
+/// `counts[a]` points, it wraps, and the next-slower axis is stepped in turn by applying that
+/// recurrence to its own stored forward-difference table, whose leading slice then re-seeds the
+/// tables of the faster axes, which is cheap since each axis only carries `degrees[a] + 1`
+/// difference cells.
+pub struct MultivariatePolynomialEvaluator<C> {
+    shape: Vec<usize>,
+    counts: Vec<usize>,
+    // tables[level] is the forward-difference tensor over the `level + 1` fastest axes, i.e. a
+    // contiguous prefix of the full tensor with every slower axis fixed at its current position.
+    tables: Vec<Vec<C>>,
+    idx: Vec<usize>,
+    point: Vec<C>,
+    initial: Vec<C>,
+    step: Vec<C>,
+    first: bool,
+    // Set when any axis was constructed with a `counts[a] == 0`, so the product domain is empty
+    // and `next` must yield nothing rather than the single point the `first` branch would
+    // otherwise emit.
+    empty: bool,
+}
+
+impl<C: Clone> MultivariatePolynomialEvaluator<C>
+where
+    for<'a> C: Mul<&'a C, Output = C>,
+    for<'a> C: Add<&'a C, Output = C>,
+    for<'a> &'a C: Add<&'a C, Output = C>,
+    for<'a> &'a C: Sub<&'a C, Output = C>,
+{
+    /// Construct a new iterator which yields the evaluations of the polynomial defined by the
+    /// coefficient tensor `coefficients` (row-major, last axis fastest) of per-axis `degrees`, on
+    /// the grid starting at `initial` with per-axis step `step`, emitting `counts[a]` points along
+    /// axis `a`.
+    ///
+    /// Panics if `coefficients.len()` does not match the product of `degrees[a] + 1`, or if
+    /// `degrees`, `initial`, `step` and `counts` don't all have the same length.
+    pub fn new(coefficients: &[C], degrees: &[usize], initial: Vec<C>, step: Vec<C>, counts: Vec<usize>) -> Self {
+        let dims = degrees.len();
+        assert_eq!(initial.len(), dims);
+        assert_eq!(step.len(), dims);
+        assert_eq!(counts.len(), dims);
+
+        let shape: Vec<usize> = degrees.iter().map(|d| d + 1).collect();
+        let total: usize = shape.iter().product();
+        assert_eq!(coefficients.len(), total);
+
+        // Collapse one axis at a time, starting from the fastest (last), turning the coefficient
+        // tensor into the full iterated forward-difference tensor: first evaluate at the
+        // `shape[a]` grid points along axis `a` (see exercise 7 in 4.6.4 of TAOCP), then build the
+        // forward differences along that axis, exactly as `PolynomialEvaluator::new` does.
+        let mut base = coefficients.to_vec();
+        let mut block = 1;
+        for a in (0..dims).rev() {
+            let points = successors(Some(initial[a].clone()), |x| Some(x + &step[a]))
+                .take(shape[a])
+                .collect::<Vec<_>>();
+            evaluate_along(&mut base, block, shape[a], &points);
+            difference_along(&mut base, block, shape[a]);
+            block *= shape[a];
+        }
+
+        // tables[level] is the contiguous prefix of `base` holding the `level + 1` fastest axes.
+        let tables = (0..dims)
+            .scan(1, |size, level| {
+                *size *= shape[dims - 1 - level];
+                Some(base[..*size].to_vec())
+            })
+            .collect();
+
+        let empty = counts.contains(&0);
+
+        Self {
+            shape,
+            counts,
+            tables,
+            idx: vec![0; dims],
+            point: initial.clone(),
+            initial,
+            step,
+            first: true,
+            empty,
+        }
+    }
+}
+
+impl<C: Clone> Iterator for MultivariatePolynomialEvaluator<C>
+where
+    for<'a> &'a C: Add<&'a C, Output = C>,
+{
+    type Item = (Vec<C>, C);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.empty {
+            return None;
+        }
+        let dims = self.shape.len();
+        if self.first {
+            self.first = false;
+        } else {
+            let mut level = 0;
+            loop {
+                let axis = dims - 1 - level;
+                if self.idx[axis] + 1 < self.counts[axis] {
+                    let block = if level == 0 { 1 } else { self.tables[level - 1].len() };
+                    advance_along(&mut self.tables[level], block, self.shape[axis]);
+                    for faster in 0..level {
+                        let size = self.tables[faster].len();
+                        self.tables[faster] = self.tables[level][..size].to_vec();
+                    }
+                    self.idx[axis] += 1;
+                    self.point[axis] = &self.point[axis] + &self.step[axis];
+                    break;
+                }
+                self.idx[axis] = 0;
+                self.point[axis] = self.initial[axis].clone();
+                level += 1;
+                if level == dims {
+                    return None;
+                }
+            }
+        }
+        Some((self.point.clone(), self.tables[0][0].clone()))
+    }
+}
+
+/// Apply the additive forward-difference recurrence `state[j] += state[j+1]` along the axis of
+/// length `axis_len` whose cells are spaced `block` elements apart, once per `block * axis_len`
+/// sized chunk of `table`.
+fn advance_along<C>(table: &mut [C], block: usize, axis_len: usize)
+where
+    for<'a> &'a C: Add<&'a C, Output = C>,
+{
+    let line_len = block * axis_len;
+    let mut base = 0;
+    while base < table.len() {
+        for j in 0..axis_len - 1 {
+            for k in 0..block {
+                table[base + j * block + k] =
+                    &table[base + j * block + k] + &table[base + (j + 1) * block + k];
+            }
+        }
+        base += line_len;
+    }
+}
+
+/// Build the forward-difference table `state[j] -= state[j-1]` along the axis of length
+/// `axis_len` whose cells are spaced `block` elements apart, once per `block * axis_len` sized
+/// chunk of `table`.
+fn difference_along<C>(table: &mut [C], block: usize, axis_len: usize)
+where
+    for<'a> &'a C: Sub<&'a C, Output = C>,
+{
+    let line_len = block * axis_len;
+    let mut base = 0;
+    while base < table.len() {
+        for k in 1..axis_len {
+            for j in (k..axis_len).rev() {
+                for x in 0..block {
+                    table[base + j * block + x] =
+                        &table[base + j * block + x] - &table[base + (j - 1) * block + x];
+                }
+            }
+        }
+        base += line_len;
+    }
+}
+
+/// Evaluate the `axis_len`-coefficient polynomial along the axis whose cells are spaced `block`
+/// elements apart at each of `points`, once per `block * axis_len` sized chunk of `table`.
+fn evaluate_along<C: Clone>(table: &mut [C], block: usize, axis_len: usize, points: &[C])
+where
+    for<'a> C: Mul<&'a C, Output = C>,
+    for<'a> C: Add<&'a C, Output = C>,
+{
+    let line_len = block * axis_len;
+    let mut base = 0;
+    while base < table.len() {
+        for k in 0..block {
+            let coefficients = (0..axis_len)
+                .map(|j| table[base + j * block + k].clone())
+                .collect::<Vec<_>>();
+            for (j, x) in points.iter().enumerate() {
+                table[base + j * block + k] = evaluate(&coefficients, x);
+            }
+        }
+        base += line_len;
+    }
+}
+
 #[test]
 fn test_evaluation() {
     let polynomial = [1, 2, 3];
@@ -92,13 +660,218 @@ fn test_evaluation() {
 #[test]
 fn test_polynomial_evaluator() {
     let polynomial = [1, 2, 3];
-    let evaluator = PolynomialEvaluator::new(&polynomial, 0, 1);
+    let evaluator = PolynomialEvaluator::new(polynomial, 0, 1);
     for (x, y) in evaluator.take(100) {
         assert_eq!(y, evaluate(&polynomial, &x))
     }
 
-    let evaluator = PolynomialEvaluator::new(&polynomial, 7, 5);
+    let evaluator = PolynomialEvaluator::new(polynomial, 7, 5);
     for (x, y) in evaluator.take(10) {
         assert_eq!(y, evaluate(&polynomial, &x))
     }
 }
+
+/// A rational-valued scalar used only by the tests below to exercise the `Div`/`From<usize>`
+/// bounds required by [`PolynomialEvaluator::coefficients`] (the built-in integer types have no
+/// `From<usize>` impl, since `usize`'s width is platform-dependent).
+#[cfg(test)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TestScalar(f64);
+
+#[cfg(test)]
+impl From<usize> for TestScalar {
+    fn from(value: usize) -> Self {
+        TestScalar(value as f64)
+    }
+}
+
+#[cfg(test)]
+impl Mul<&TestScalar> for TestScalar {
+    type Output = TestScalar;
+    fn mul(self, rhs: &TestScalar) -> TestScalar {
+        TestScalar(self.0 * rhs.0)
+    }
+}
+
+#[cfg(test)]
+impl Add<&TestScalar> for TestScalar {
+    type Output = TestScalar;
+    fn add(self, rhs: &TestScalar) -> TestScalar {
+        TestScalar(self.0 + rhs.0)
+    }
+}
+
+#[cfg(test)]
+impl Div<&TestScalar> for TestScalar {
+    type Output = TestScalar;
+    fn div(self, rhs: &TestScalar) -> TestScalar {
+        TestScalar(self.0 / rhs.0)
+    }
+}
+
+#[cfg(test)]
+impl<'a> Add<&'a TestScalar> for &'a TestScalar {
+    type Output = TestScalar;
+    fn add(self, rhs: &'a TestScalar) -> TestScalar {
+        TestScalar(self.0 + rhs.0)
+    }
+}
+
+#[cfg(test)]
+impl<'a> Sub<&'a TestScalar> for &'a TestScalar {
+    type Output = TestScalar;
+    fn sub(self, rhs: &'a TestScalar) -> TestScalar {
+        TestScalar(self.0 - rhs.0)
+    }
+}
+
+#[test]
+fn test_from_samples_roundtrip() {
+    let polynomial = [
+        TestScalar(1.0),
+        TestScalar(2.0),
+        TestScalar(3.0),
+    ];
+    let initial = TestScalar(7.0);
+    let step = TestScalar(5.0);
+
+    let samples: Vec<TestScalar> = (0..polynomial.len())
+        .map(|k| evaluate(&polynomial, &TestScalar(initial.0 + k as f64 * step.0)))
+        .collect();
+
+    let evaluator = PolynomialEvaluator::from_samples(&samples, initial, step);
+    assert_eq!(evaluator.coefficients(), polynomial);
+
+    for (x, y) in evaluator.take(20) {
+        assert_eq!(y, evaluate(&polynomial, &x))
+    }
+}
+
+#[test]
+fn test_polynomial_arithmetic() {
+    // p(x) = 1 + 2x + 3x^2, q(x) = 5 + 7x
+    let p = Polynomial::new(vec![TestScalar(1.0), TestScalar(2.0), TestScalar(3.0)]);
+    let q = Polynomial::new(vec![TestScalar(5.0), TestScalar(7.0)]);
+
+    let x = TestScalar(2.0);
+    let sum = p.add(&q);
+    assert_eq!(sum.eval(&x).0, p.eval(&x).0 + q.eval(&x).0);
+
+    let difference = p.sub(&q);
+    assert_eq!(difference.eval(&x).0, p.eval(&x).0 - q.eval(&x).0);
+
+    let product = p.mul(&q);
+    assert_eq!(product.eval(&x).0, p.eval(&x).0 * q.eval(&x).0);
+    assert_eq!(product.degree(), p.degree() + q.degree());
+
+    let cubed = p.pow(3);
+    assert_eq!(cubed.eval(&x).0, p.eval(&x).0.powi(3));
+
+    let scaled = p.scale(&TestScalar(2.0));
+    assert_eq!(scaled.coefficients(), [TestScalar(2.0), TestScalar(4.0), TestScalar(6.0)]);
+
+    let divided = scaled.scale_div(&TestScalar(2.0));
+    assert_eq!(divided.coefficients(), p.coefficients());
+
+    let mut with_trailing_zero = Polynomial::new(vec![TestScalar(1.0), TestScalar(0.0)]);
+    with_trailing_zero.trim();
+    assert_eq!(with_trailing_zero.coefficients(), [TestScalar(1.0)]);
+}
+
+#[test]
+fn test_polynomial_evaluator_from_polynomial() {
+    // p(x) = 1 + 2x + 3x^2
+    let p = Polynomial::new(vec![TestScalar(1.0), TestScalar(2.0), TestScalar(3.0)]);
+    let initial = TestScalar(7.0);
+    let step = TestScalar(5.0);
+
+    let evaluator = PolynomialEvaluator::new(&p, initial, step);
+    for (x, y) in evaluator.take(20) {
+        assert_eq!(y.0, p.eval(&x).0)
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_tabulate_parallel_matches_sequential() {
+    let polynomial = [TestScalar(1.0), TestScalar(2.0), TestScalar(3.0)];
+    let initial = TestScalar(7.0);
+    let step = TestScalar(5.0);
+
+    let sequential: Vec<_> = PolynomialEvaluator::new(polynomial, initial, step)
+        .take(17)
+        .collect();
+
+    for threads in 1..=8 {
+        let parallel = tabulate_parallel(&polynomial, initial, step, 17, threads);
+        assert_eq!(parallel, sequential, "mismatch for threads = {threads}");
+    }
+
+    // `count` smaller than a single block's `degree + 1` must still work.
+    for threads in 1..=8 {
+        let parallel = tabulate_parallel(&polynomial, initial, step, 2, threads);
+        assert_eq!(parallel, sequential[..2], "mismatch for threads = {threads}");
+    }
+}
+
+/// Evaluate the polynomial with the given row-major (last axis fastest) coefficient tensor at a
+/// single grid point, for comparison against `MultivariatePolynomialEvaluator`.
+#[cfg(test)]
+fn evaluate_multivariate(coefficients: &[i64], degrees: &[usize], point: &[i64]) -> i64 {
+    let shape: Vec<usize> = degrees.iter().map(|d| d + 1).collect();
+    let mut strides = vec![1; degrees.len()];
+    for a in (0..degrees.len() - 1).rev() {
+        strides[a] = strides[a + 1] * shape[a + 1];
+    }
+    (0..coefficients.len())
+        .map(|flat| {
+            let mut rest = flat;
+            (0..degrees.len())
+                .map(|a| {
+                    let power = rest / strides[a];
+                    rest %= strides[a];
+                    point[a].pow(power as u32)
+                })
+                .fold(coefficients[flat], |term, factor| term * factor)
+        })
+        .sum()
+}
+
+#[test]
+fn test_multivariate_polynomial_evaluator() {
+    // P(x, y) = sum c[i][j] * x^i * y^j, degree 2 in x, degree 1 in y.
+    let coefficients = [1, 2, 3, 4, 5, 6];
+    let degrees = [2, 1];
+    let initial = vec![10, 100];
+    let step = vec![3, 7];
+    let counts = vec![5, 4];
+
+    let evaluator = MultivariatePolynomialEvaluator::new(
+        &coefficients,
+        &degrees,
+        initial,
+        step,
+        counts.clone(),
+    );
+    let mut seen = 0;
+    for (point, value) in evaluator {
+        assert_eq!(value, evaluate_multivariate(&coefficients, &degrees, &point));
+        seen += 1;
+    }
+    assert_eq!(seen, counts.iter().product::<usize>());
+}
+
+#[test]
+fn test_multivariate_polynomial_evaluator_zero_count_is_empty() {
+    // A `0` count along any axis means an empty product domain, so no points should be yielded.
+    let coefficients = [1, 2, 3, 4, 5, 6];
+    let degrees = [2, 1];
+
+    let evaluator =
+        MultivariatePolynomialEvaluator::new(&coefficients, &degrees, vec![10, 100], vec![3, 7], vec![0, 4]);
+    assert_eq!(evaluator.count(), 0);
+
+    let evaluator =
+        MultivariatePolynomialEvaluator::new(&coefficients, &degrees, vec![10, 100], vec![3, 7], vec![5, 0]);
+    assert_eq!(evaluator.count(), 0);
+}